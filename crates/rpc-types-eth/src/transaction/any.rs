@@ -0,0 +1,32 @@
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+use serde::{Deserialize, Serialize};
+
+/// A transaction envelope for the `Any` network.
+///
+/// Holds the fields common to every transaction type without committing to a specific typed
+/// envelope; fields that only apply to some transaction types (e.g. `max_fee_per_gas` for
+/// EIP-1559 and later) are optional since the concrete variant isn't known statically.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnyTxEnvelope {
+    /// Transaction hash.
+    pub hash: TxHash,
+    /// Recipient, `None` for a contract creation.
+    pub to: Option<Address>,
+    /// Transferred value.
+    pub value: U256,
+    /// Gas limit.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas: u128,
+    /// Gas price paid by legacy and EIP-2930 transactions.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub gas_price: Option<u128>,
+    /// Max fee per gas, for EIP-1559 and later transactions.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub max_fee_per_gas: Option<u128>,
+    /// Max priority fee per gas, for EIP-1559 and later transactions.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub max_priority_fee_per_gas: Option<u128>,
+    /// Call data.
+    pub input: Bytes,
+}
@@ -0,0 +1,158 @@
+use alloy_consensus::Eip658Value;
+use alloy_primitives::{Address, BlockHash, Bloom, Bytes, TxHash, B256};
+use serde::{Deserialize, Serialize};
+
+/// A log emitted by a transaction, as returned in an `eth_getTransactionReceipt` response.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    /// Contract that emitted the log.
+    pub address: Address,
+    /// Topics of the log.
+    pub topics: Vec<B256>,
+    /// Data of the log.
+    pub data: Bytes,
+    /// Hash of the block this log was included within.
+    #[serde(default)]
+    pub block_hash: Option<BlockHash>,
+    /// Number of the block this log was included within.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub block_number: Option<u64>,
+    /// Hash of the transaction that emitted this log.
+    #[serde(default)]
+    pub transaction_hash: Option<TxHash>,
+    /// Index of the transaction that emitted this log within its block.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub transaction_index: Option<u64>,
+    /// Index of this log within the block.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub log_index: Option<u64>,
+    /// Index of this log within the transaction that emitted it.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub transaction_log_index: Option<u64>,
+    /// Whether this log was removed due to a chain reorganization.
+    #[serde(default)]
+    pub removed: bool,
+}
+
+/// Transaction receipt
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    /// Hash of the transaction this receipt is for.
+    pub transaction_hash: TxHash,
+    /// Index within the block.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub transaction_index: Option<u64>,
+    /// Hash of the block this transaction was included within.
+    #[serde(default)]
+    pub block_hash: Option<BlockHash>,
+    /// Number of the block this transaction was included within.
+    #[serde(default, with = "alloy_serde::quantity::opt")]
+    pub block_number: Option<u64>,
+    /// Whether the transaction succeeded (post-Byzantium) or the post-state root
+    /// (pre-Byzantium).
+    #[serde(flatten)]
+    pub status: Eip658Value,
+    /// Cumulative gas used by the block up to and including this transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub cumulative_gas_used: u128,
+    /// Gas used by this transaction alone.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u128,
+    /// Effective gas price paid by this transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub effective_gas_price: u128,
+    /// Bloom filter over this transaction's logs.
+    pub logs_bloom: Bloom,
+    /// Address of the sender.
+    pub from: Address,
+    /// Address of the receiver, `None` if it's a contract creation.
+    pub to: Option<Address>,
+    /// Address of the created contract, if any.
+    pub contract_address: Option<Address>,
+    /// Logs emitted by this transaction.
+    pub logs: Vec<Log>,
+}
+
+/// `TransactionReceipt` for the `Any` network, carrying unrecognized fields alongside the
+/// common ones above.
+pub type AnyTransactionReceipt = alloy_serde::WithOtherFields<TransactionReceipt>;
+
+/// Assigns the block-wide `log_index` and per-transaction `transaction_log_index` across an
+/// ordered slice of a block's receipts.
+///
+/// Receipts fetched or reconstructed independently (e.g. while handling a reorg) don't carry
+/// log indices until they're placed in block order; this walks `receipts` in that order,
+/// accumulating a running `log_index` across the whole block and resetting
+/// `transaction_log_index` to `0` at each transaction boundary, matching how execution clients
+/// materialize `eth_getTransactionReceipt` responses.
+pub fn assign_log_indices(receipts: &mut [TransactionReceipt]) {
+    let mut log_index = 0u64;
+    for receipt in receipts {
+        for (transaction_log_index, log) in receipt.logs.iter_mut().enumerate() {
+            log.log_index = Some(log_index);
+            log.transaction_log_index = Some(transaction_log_index as u64);
+            log_index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(address: Address) -> Log {
+        Log {
+            address,
+            topics: vec![],
+            data: Bytes::new(),
+            block_hash: None,
+            block_number: None,
+            transaction_hash: None,
+            transaction_index: None,
+            log_index: None,
+            transaction_log_index: None,
+            removed: false,
+        }
+    }
+
+    fn receipt(hash: TxHash, num_logs: usize) -> TransactionReceipt {
+        TransactionReceipt {
+            transaction_hash: hash,
+            transaction_index: None,
+            block_hash: None,
+            block_number: None,
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 0,
+            gas_used: 0,
+            effective_gas_price: 0,
+            logs_bloom: Bloom::ZERO,
+            from: Address::ZERO,
+            to: None,
+            contract_address: None,
+            logs: (0..num_logs).map(|_| log(Address::ZERO)).collect(),
+        }
+    }
+
+    #[test]
+    fn assigns_running_and_per_transaction_indices() {
+        let mut receipts = vec![
+            receipt(TxHash::with_last_byte(1), 2),
+            receipt(TxHash::with_last_byte(2), 0),
+            receipt(TxHash::with_last_byte(3), 1),
+        ];
+
+        assign_log_indices(&mut receipts);
+
+        let log_indices: Vec<_> =
+            receipts.iter().flat_map(|r| r.logs.iter().map(|l| l.log_index.unwrap())).collect();
+        assert_eq!(log_indices, vec![0, 1, 2]);
+
+        let tx_log_indices: Vec<_> = receipts
+            .iter()
+            .flat_map(|r| r.logs.iter().map(|l| l.transaction_log_index.unwrap()))
+            .collect();
+        assert_eq!(tx_log_indices, vec![0, 1, 0]);
+    }
+}